@@ -1,40 +1,311 @@
 use solana_program::{entrypoint::ProgramResult, program_error::ProgramError, pubkey::Pubkey};
+use std::collections::HashMap;
+
+/// A typed view over the raw support-signal bitmask, where bit `i` corresponds to
+/// slot `i` of `current_features`/`next_features`. Out-of-range slot indices
+/// wrap modulo 8 rather than panicking, since only 8 slots ever exist.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SupportBitmask(u8);
+
+impl SupportBitmask {
+    pub fn new(bits: u8) -> Self {
+        Self(bits)
+    }
+
+    pub fn set(&mut self, slot: usize) {
+        self.0 |= 1 << (slot % 8);
+    }
+
+    pub fn clear(&mut self, slot: usize) {
+        self.0 &= !(1 << (slot % 8));
+    }
+
+    pub fn is_set(&self, slot: usize) -> bool {
+        self.0 & (1 << (slot % 8)) != 0
+    }
+
+    /// Iterates over the slot indices set in this bitmask.
+    pub fn iter_set(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..8).filter(move |slot| self.is_set(*slot))
+    }
+
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+}
+
+/// Where a staged feature sits in its activation lifecycle.
+///
+/// Legal transitions are `Staged -> Signaling`, driven by the first support
+/// signal a staged feature receives, and `Staged|Signaling -> Activated` or
+/// `Staged|Signaling -> Rejected`, decided at rollover.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeatureStatus {
+    Staged,
+    Signaling,
+    Activated,
+    Rejected,
+}
+
+impl FeatureStatus {
+    fn transition(self, to: FeatureStatus) -> Result<FeatureStatus, ProgramError> {
+        use FeatureStatus::*;
+        match (self, to) {
+            (current, new) if current == new => Ok(new),
+            (Staged, Signaling) => Ok(to),
+            (Staged, Activated) | (Staged, Rejected) => Ok(to),
+            (Signaling, Activated) | (Signaling, Rejected) => Ok(to),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+/// A terminal lifecycle decision recorded at rollover, so callers can look back
+/// at why a feature did or didn't go live.
+#[derive(Clone, Copy, Debug)]
+pub struct FeatureLifecycleEvent {
+    pub epoch: u64,
+    pub feature_id: Pubkey,
+    pub status: FeatureStatus,
+}
+
+// How many lifecycle events to retain; older events are dropped.
+const HISTORY_CAPACITY: usize = 16;
+
+/// A program-log-equivalent event, appended to `SimulatedProgramContext`'s log
+/// buffer so an off-chain watcher can reconstruct the staging/activation
+/// timeline without reading account state directly (in the real program this
+/// would be a `sol_log_data` call).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeatureGateEvent {
+    Staged { epoch: u64, feature_id: Pubkey },
+    Signaling { epoch: u64, feature_id: Pubkey },
+    Activated { epoch: u64, feature_id: Pubkey },
+    Dropped { epoch: u64, feature_id: Pubkey },
+}
 
 pub struct StagedFeatures {
     current_epoch: u64,
     current_features: [Pubkey; 8],
+    current_status: [FeatureStatus; 8],
     next_epoch: u64,
     next_features: [Pubkey; 8],
+    next_status: [FeatureStatus; 8],
+    // Whether each staged slot in `next_features` is required: a validator that
+    // doesn't signal support for a required, staged slot is rejected outright.
+    required: [bool; 8],
+    // Stake that has signaled support for each slot in `next_features`, accumulated
+    // over the epoch leading up to the next rollover.
+    support_tally: [u64; 8],
+    // Total stake active on the cluster, used as the denominator for the
+    // activation threshold.
+    total_active_stake: u64,
+    // Basis points of `total_active_stake` a staged feature must accumulate support
+    // from before it's allowed to activate (eg. 9_500 == 95%).
+    activation_threshold_bps: u16,
+    // Every slot each validator has signaled support for this epoch (the union of
+    // its signals), keyed by validator identity, so a repeated signal doesn't
+    // double-count stake.
+    signaled: HashMap<Pubkey, u8>,
+    // The most recent activation/rejection decisions, oldest first.
+    history: Vec<FeatureLifecycleEvent>,
+}
+
+// Removes bit `index` from `bits` and shifts every higher bit down by one, so a
+// validator's signaled bitmask tracks the same slots after `unstage_feature`
+// compacts the underlying arrays.
+fn shift_bitmask_after_removal(bits: u8, index: usize) -> u8 {
+    let low = bits & ((1u8 << index) - 1);
+    let high = if index >= 7 {
+        0
+    } else {
+        (bits >> (index + 1)) << index
+    };
+    low | high
 }
 
 impl StagedFeatures {
-    pub fn maybe_update(&mut self, current_epoch: u64) {
+    pub fn maybe_update(
+        &mut self,
+        current_epoch: u64,
+    ) -> Result<Vec<FeatureGateEvent>, ProgramError> {
+        let mut events = Vec::new();
         if current_epoch >= self.next_epoch {
+            let rollover_epoch = self.next_epoch;
+            for i in 0..8 {
+                if self.next_features[i] == Pubkey::default() {
+                    // Nothing was staged in this slot; nothing to promote, and any
+                    // terminal status left over from a prior epoch no longer
+                    // describes anything, so it resets along with the pubkey.
+                    self.current_features[i] = Pubkey::default();
+                    self.current_status[i] = FeatureStatus::Staged;
+                    continue;
+                }
+                let feature_id = self.next_features[i];
+                let supported = (self.support_tally[i] as u128) * 10_000
+                    >= (self.total_active_stake as u128) * (self.activation_threshold_bps as u128);
+                let decision = if supported {
+                    FeatureStatus::Activated
+                } else {
+                    FeatureStatus::Rejected
+                };
+                self.current_status[i] = self.next_status[i].transition(decision)?;
+                self.current_features[i] = if supported {
+                    feature_id
+                } else {
+                    // Didn't accumulate enough stake-weighted support in time; drop it
+                    // rather than activating.
+                    Pubkey::default()
+                };
+                self.record_history(rollover_epoch, feature_id, decision);
+                events.push(if supported {
+                    FeatureGateEvent::Activated {
+                        epoch: rollover_epoch,
+                        feature_id,
+                    }
+                } else {
+                    FeatureGateEvent::Dropped {
+                        epoch: rollover_epoch,
+                        feature_id,
+                    }
+                });
+            }
             self.current_epoch = self.next_epoch;
-            self.current_features = self.next_features;
             self.next_epoch = current_epoch + 1;
             self.next_features = [Pubkey::default(); 8];
+            self.next_status = [FeatureStatus::Staged; 8];
+            self.required = [false; 8];
+            self.support_tally = [0; 8];
+            self.signaled.clear();
         }
+        Ok(events)
+    }
+
+    fn record_history(&mut self, epoch: u64, feature_id: Pubkey, status: FeatureStatus) {
+        self.history.push(FeatureLifecycleEvent {
+            epoch,
+            feature_id,
+            status,
+        });
+        if self.history.len() > HISTORY_CAPACITY {
+            self.history.remove(0);
+        }
+    }
+
+    /// The most recent activation/rejection decisions, oldest first, so callers
+    /// can query why a feature did or did not go live.
+    pub fn history(&self) -> &[FeatureLifecycleEvent] {
+        &self.history
     }
 
     pub fn stage_feature(
         &mut self,
         target_epoch: u64,
         feature_id: Pubkey,
+        required: bool,
     ) -> Result<(), ProgramError> {
         if target_epoch != self.next_epoch {
             // Target epoch is not the next epoch.
             return Err(ProgramError::InvalidArgument);
         }
-        for feature in self.next_features.iter_mut() {
-            if *feature == Pubkey::default() {
-                *feature = feature_id;
+        for i in 0..8 {
+            if self.next_features[i] == Pubkey::default() {
+                self.next_features[i] = feature_id;
+                self.next_status[i] = FeatureStatus::Staged;
+                self.required[i] = required;
                 return Ok(());
             }
         }
         // Staged features is full.
         Err(ProgramError::InvalidArgument)
     }
+
+    pub fn unstage_feature(
+        &mut self,
+        target_epoch: u64,
+        feature_id: Pubkey,
+    ) -> Result<(), ProgramError> {
+        if target_epoch != self.next_epoch {
+            // Target epoch is not the next epoch.
+            return Err(ProgramError::InvalidArgument);
+        }
+        let index = self
+            .next_features
+            .iter()
+            .position(|feature| *feature == feature_id)
+            .ok_or(ProgramError::InvalidArgument)?;
+        // Shift everything after the removed slot down by one so there's no hole
+        // ahead of later-staged features, keeping `required`/`support_tally`/
+        // `next_status` in lockstep with the slot they describe.
+        for i in index..7 {
+            self.next_features[i] = self.next_features[i + 1];
+            self.next_status[i] = self.next_status[i + 1];
+            self.required[i] = self.required[i + 1];
+            self.support_tally[i] = self.support_tally[i + 1];
+        }
+        self.next_features[7] = Pubkey::default();
+        self.next_status[7] = FeatureStatus::Staged;
+        self.required[7] = false;
+        self.support_tally[7] = 0;
+        // Every validator's signaled bitmask is keyed by slot index, so it needs
+        // the same shift applied to it, or bit `index` (now meaning a different
+        // feature) would be misread as already having been signaled for.
+        for bits in self.signaled.values_mut() {
+            *bits = shift_bitmask_after_removal(*bits, index);
+        }
+        Ok(())
+    }
+
+    pub fn signal_support(
+        &mut self,
+        validator: Pubkey,
+        stake: u64,
+        bitmask: SupportBitmask,
+    ) -> Result<Vec<FeatureGateEvent>, ProgramError> {
+        // A validator must signal support for every staged slot marked required;
+        // leaving one unset is a hard rejection, not a partial signal.
+        for i in 0..8 {
+            if self.required[i] && self.next_features[i] != Pubkey::default() && !bitmask.is_set(i)
+            {
+                return Err(ProgramError::InvalidArgument);
+            }
+        }
+
+        // The first support signal for a staged slot moves it out of `Staged` and
+        // into `Signaling`; later signals for the same slot are idempotent.
+        let mut events = Vec::new();
+        for i in bitmask.iter_set() {
+            if self.next_features[i] != Pubkey::default() {
+                let previous_status = self.next_status[i];
+                self.next_status[i] = self.next_status[i].transition(FeatureStatus::Signaling)?;
+                if previous_status != FeatureStatus::Signaling {
+                    events.push(FeatureGateEvent::Signaling {
+                        epoch: self.next_epoch,
+                        feature_id: self.next_features[i],
+                    });
+                }
+            }
+        }
+
+        // An empty bitmask supports nothing, so there's no stake to tally and no
+        // need to occupy a `signaled` entry for it (this is also how a rollover
+        // gets triggered without an accompanying real signal).
+        if bitmask.bits() != 0 {
+            // Only count stake for bits this validator hasn't already signaled
+            // this epoch, so a repeat (or redundant) signal can't inflate the
+            // tally.
+            let previously_signaled = self.signaled.get(&validator).copied().unwrap_or(0);
+            let newly_signaled = bitmask.bits() & !previously_signaled;
+            self.signaled
+                .insert(validator, previously_signaled | bitmask.bits());
+            for (i, tally) in self.support_tally.iter_mut().enumerate() {
+                if newly_signaled & (1 << i) != 0 {
+                    *tally += stake;
+                }
+            }
+        }
+        Ok(events)
+    }
 }
 
 pub struct SimulatedProgramContext {
@@ -42,32 +313,69 @@ pub struct SimulatedProgramContext {
     clock_sysvar_epoch: u64,
     // The Staged Features PDA.
     staged_features_pda: StagedFeatures,
+    // Stand-in for the program's log output (`sol_log_data` in the real program).
+    event_log: Vec<FeatureGateEvent>,
+}
+
+impl SimulatedProgramContext {
+    /// The events emitted so far, oldest first.
+    pub fn events(&self) -> &[FeatureGateEvent] {
+        &self.event_log
+    }
 }
 
 pub fn simulate_stage_feature_instruction(
     context: &mut SimulatedProgramContext,
     target_epoch: u64,
     feature_id: Pubkey,
+    required: bool,
 ) -> ProgramResult {
     /* Checks */
+    let events = context
+        .staged_features_pda
+        .maybe_update(context.clock_sysvar_epoch)?;
+    context.event_log.extend(events);
     context
         .staged_features_pda
-        .maybe_update(context.clock_sysvar_epoch);
+        .stage_feature(target_epoch, feature_id, required)?;
+    context.event_log.push(FeatureGateEvent::Staged {
+        epoch: target_epoch,
+        feature_id,
+    });
+    Ok(())
+}
+
+pub fn simulate_unstage_feature_instruction(
+    context: &mut SimulatedProgramContext,
+    target_epoch: u64,
+    feature_id: Pubkey,
+) -> ProgramResult {
+    /* Checks */
+    let events = context
+        .staged_features_pda
+        .maybe_update(context.clock_sysvar_epoch)?;
+    context.event_log.extend(events);
     context
         .staged_features_pda
-        .stage_feature(target_epoch, feature_id)?;
+        .unstage_feature(target_epoch, feature_id)?;
     Ok(())
 }
 
 pub fn simulate_signal_support_instruction(
     context: &mut SimulatedProgramContext,
-    _bitmask: u8,
+    validator: Pubkey,
+    stake: u64,
+    bitmask: SupportBitmask,
 ) -> ProgramResult {
     /* Checks */
-    context
+    let rollover_events = context
         .staged_features_pda
-        .maybe_update(context.clock_sysvar_epoch);
-    /* Signal support logic... */
+        .maybe_update(context.clock_sysvar_epoch)?;
+    context.event_log.extend(rollover_events);
+    let signaling_events = context
+        .staged_features_pda
+        .signal_support(validator, stake, bitmask)?;
+    context.event_log.extend(signaling_events);
     Ok(())
 }
 
@@ -79,22 +387,77 @@ fn test() {
         staged_features_pda: StagedFeatures {
             current_epoch: 0,
             current_features: [Pubkey::default(); 8],
+            current_status: [FeatureStatus::Staged; 8],
             next_epoch: 1,
             next_features: [Pubkey::default(); 8],
+            next_status: [FeatureStatus::Staged; 8],
+            required: [false; 8],
+            support_tally: [0; 8],
+            total_active_stake: 0,
+            activation_threshold_bps: 9_500,
+            signaled: HashMap::new(),
+            history: Vec::new(),
         },
+        event_log: Vec::new(),
     };
 
     // Fail trying to stage a feature for epoch 2.
     assert_eq!(
-        simulate_stage_feature_instruction(&mut context, 2, Pubkey::new_unique()),
+        simulate_stage_feature_instruction(&mut context, 2, Pubkey::new_unique(), false),
         Err(ProgramError::InvalidArgument)
     );
 
+    // Stage three placeholder features, unstage the middle one, and confirm the
+    // array compacts with no hole left behind.
+    let placeholder_a = Pubkey::new_unique();
+    let placeholder_b = Pubkey::new_unique();
+    let placeholder_c = Pubkey::new_unique();
+    simulate_stage_feature_instruction(&mut context, 1, placeholder_a, false).unwrap();
+    simulate_stage_feature_instruction(&mut context, 1, placeholder_b, false).unwrap();
+    simulate_stage_feature_instruction(&mut context, 1, placeholder_c, false).unwrap();
+    simulate_unstage_feature_instruction(&mut context, 1, placeholder_b).unwrap();
+    assert_eq!(
+        &context.staged_features_pda.next_features[..3],
+        &[placeholder_a, placeholder_c, Pubkey::default()]
+    );
+    // Unstaging a feature that isn't staged (anymore) fails.
+    assert_eq!(
+        simulate_unstage_feature_instruction(&mut context, 1, placeholder_b),
+        Err(ProgramError::InvalidArgument)
+    );
+
+    // A validator signals support for slot 0 (`placeholder_a`), then an
+    // unrelated slot gets unstaged. Re-signaling the identical bitmask must not
+    // double-count the validator's stake against slot 0: unstaging has to remap
+    // the validator's signaled bitmask to the post-compaction slot layout, not
+    // just blow it away.
+    let placeholder_validator = Pubkey::new_unique();
+    simulate_signal_support_instruction(
+        &mut context,
+        placeholder_validator,
+        100,
+        SupportBitmask::new(0b0000_0001),
+    )
+    .unwrap();
+    assert_eq!(context.staged_features_pda.support_tally[0], 100);
+    simulate_unstage_feature_instruction(&mut context, 1, placeholder_c).unwrap();
+    simulate_signal_support_instruction(
+        &mut context,
+        placeholder_validator,
+        100,
+        SupportBitmask::new(0b0000_0001),
+    )
+    .unwrap();
+    assert_eq!(context.staged_features_pda.support_tally[0], 100);
+
+    // Clean up the placeholder so the rest of the walkthrough starts fresh.
+    simulate_unstage_feature_instruction(&mut context, 1, placeholder_a).unwrap();
+
     // Stage a few features.
     let mock_feature_id_epoch_1 = Pubkey::new_unique();
-    simulate_stage_feature_instruction(&mut context, 1, mock_feature_id_epoch_1).unwrap();
-    simulate_stage_feature_instruction(&mut context, 1, mock_feature_id_epoch_1).unwrap();
-    simulate_stage_feature_instruction(&mut context, 1, mock_feature_id_epoch_1).unwrap();
+    simulate_stage_feature_instruction(&mut context, 1, mock_feature_id_epoch_1, false).unwrap();
+    simulate_stage_feature_instruction(&mut context, 1, mock_feature_id_epoch_1, false).unwrap();
+    simulate_stage_feature_instruction(&mut context, 1, mock_feature_id_epoch_1, false).unwrap();
 
     // Current features are unchanged.
     assert_eq!(context.staged_features_pda.current_epoch, 0);
@@ -128,22 +491,30 @@ fn test() {
         ]
     );
 
-    // Move the epoch forward to 1.
+    // Move the epoch forward to 1. With no active stake configured yet, the
+    // threshold check is vacuously satisfied, so rollover still behaves like an
+    // unconditional promotion.
     context.clock_sysvar_epoch = 1;
 
     // Fail trying to stage a feature for epoch 3.
     assert_eq!(
-        simulate_stage_feature_instruction(&mut context, 3, Pubkey::new_unique()),
+        simulate_stage_feature_instruction(&mut context, 3, Pubkey::new_unique(), false),
         Err(ProgramError::InvalidArgument)
     );
 
     // Succeed trying to stage a few features for epoch 2.
     let mock_feature_id_epoch_2 = Pubkey::new_unique();
-    simulate_stage_feature_instruction(&mut context, 2, mock_feature_id_epoch_2).unwrap();
-    simulate_stage_feature_instruction(&mut context, 2, mock_feature_id_epoch_2).unwrap();
-    simulate_stage_feature_instruction(&mut context, 2, mock_feature_id_epoch_2).unwrap();
-    simulate_stage_feature_instruction(&mut context, 2, mock_feature_id_epoch_2).unwrap();
-    simulate_stage_feature_instruction(&mut context, 2, mock_feature_id_epoch_2).unwrap();
+    simulate_stage_feature_instruction(&mut context, 2, mock_feature_id_epoch_2, false).unwrap();
+    simulate_stage_feature_instruction(&mut context, 2, mock_feature_id_epoch_2, false).unwrap();
+    simulate_stage_feature_instruction(&mut context, 2, mock_feature_id_epoch_2, false).unwrap();
+    simulate_stage_feature_instruction(&mut context, 2, mock_feature_id_epoch_2, false).unwrap();
+    simulate_stage_feature_instruction(&mut context, 2, mock_feature_id_epoch_2, false).unwrap();
+
+    // Stage one more feature for epoch 2 and mark it required: a validator must
+    // explicitly signal support for it or be rejected outright.
+    let mock_required_feature_epoch_2 = Pubkey::new_unique();
+    simulate_stage_feature_instruction(&mut context, 2, mock_required_feature_epoch_2, true)
+        .unwrap();
 
     // Current features are from epoch 1.
     assert_eq!(context.staged_features_pda.current_epoch, 1);
@@ -171,19 +542,93 @@ fn test() {
             mock_feature_id_epoch_2,
             mock_feature_id_epoch_2,
             mock_feature_id_epoch_2,
-            Pubkey::default(),
+            mock_required_feature_epoch_2,
             Pubkey::default(),
             Pubkey::default(),
         ]
     );
 
-    // Move the epoch forward to 2.
-    context.clock_sysvar_epoch = 2;
+    // Now wire up some active stake, and have validators signal support for
+    // epoch 2's staged features before the rollover happens.
+    context.staged_features_pda.total_active_stake = 100;
+
+    let validator_a = Pubkey::new_unique();
+    let validator_b = Pubkey::new_unique();
+    let validator_c = Pubkey::new_unique();
+
+    // Validator C tries to signal without the required slot (5) set, and is
+    // rejected outright rather than having its stake partially counted.
+    assert_eq!(
+        simulate_signal_support_instruction(
+            &mut context,
+            validator_c,
+            50,
+            SupportBitmask::new(0b0001_1111)
+        ),
+        Err(ProgramError::InvalidArgument)
+    );
+
+    // Validator A (70 stake) supports all 5 optional slots plus the required one.
+    simulate_signal_support_instruction(
+        &mut context,
+        validator_a,
+        70,
+        SupportBitmask::new(0b0011_1111),
+    )
+    .unwrap();
+    // A repeat signal from validator A must not double-count its stake.
+    simulate_signal_support_instruction(
+        &mut context,
+        validator_a,
+        70,
+        SupportBitmask::new(0b0011_1111),
+    )
+    .unwrap();
+    // Validator B (30 stake) only supports the first 3 optional slots, plus the
+    // required one.
+    simulate_signal_support_instruction(
+        &mut context,
+        validator_b,
+        30,
+        SupportBitmask::new(0b0010_0111),
+    )
+    .unwrap();
 
-    // Simulate the first validator sending a support signal for epoch 2.
-    simulate_signal_support_instruction(&mut context, 0b00000001).unwrap();
+    // Slots 0-2 and 5 have 100/100 stake-weighted support (>= 95% threshold), but
+    // slots 3-4 only have 70/100 (< 95% threshold).
+    assert_eq!(
+        context.staged_features_pda.support_tally,
+        [100, 100, 100, 70, 70, 100, 0, 0]
+    );
 
-    // Current features are from epoch 2.
+    // Every staged slot moved to `Signaling` the moment it received its first
+    // support signal.
+    assert_eq!(
+        context.staged_features_pda.next_status,
+        [
+            FeatureStatus::Signaling,
+            FeatureStatus::Signaling,
+            FeatureStatus::Signaling,
+            FeatureStatus::Signaling,
+            FeatureStatus::Signaling,
+            FeatureStatus::Signaling,
+            FeatureStatus::Staged,
+            FeatureStatus::Staged,
+        ]
+    );
+
+    // Move the epoch forward to 2, triggering rollover.
+    context.clock_sysvar_epoch = 2;
+    simulate_signal_support_instruction(
+        &mut context,
+        Pubkey::new_unique(),
+        0,
+        SupportBitmask::default(),
+    )
+    .unwrap();
+
+    // Only the slots that cleared the activation threshold were promoted; the
+    // rest were dropped rather than activated.
     assert_eq!(context.staged_features_pda.current_epoch, 2);
     assert_eq!(
         &context.staged_features_pda.current_features,
@@ -191,11 +636,63 @@ fn test() {
             mock_feature_id_epoch_2,
             mock_feature_id_epoch_2,
             mock_feature_id_epoch_2,
-            mock_feature_id_epoch_2,
-            mock_feature_id_epoch_2,
             Pubkey::default(),
             Pubkey::default(),
+            mock_required_feature_epoch_2,
+            Pubkey::default(),
             Pubkey::default(),
         ]
     );
+
+    // The tally and per-validator signal set reset for the new epoch.
+    assert_eq!(context.staged_features_pda.support_tally, [0; 8]);
+    assert!(context.staged_features_pda.signaled.is_empty());
+
+    // Each staged slot's lifecycle was decided: the well-supported ones
+    // activated, the under-supported ones were rejected.
+    assert_eq!(
+        context.staged_features_pda.current_status,
+        [
+            FeatureStatus::Activated,
+            FeatureStatus::Activated,
+            FeatureStatus::Activated,
+            FeatureStatus::Rejected,
+            FeatureStatus::Rejected,
+            FeatureStatus::Activated,
+            FeatureStatus::Staged,
+            FeatureStatus::Staged,
+        ]
+    );
+
+    // The rollover recorded a lifecycle event for every staged slot decided so
+    // far (3 from epoch 1's rollover, 6 from epoch 2's), so a caller can later
+    // ask why a given feature did or didn't go live.
+    let history = context.staged_features_pda.history();
+    assert_eq!(history.len(), 9);
+    assert_eq!(history[6].feature_id, mock_feature_id_epoch_2);
+    assert_eq!(history[6].status, FeatureStatus::Rejected);
+    assert_eq!(history[8].feature_id, mock_required_feature_epoch_2);
+    assert_eq!(history[8].status, FeatureStatus::Activated);
+
+    // The context's event log lets an off-chain watcher reconstruct the same
+    // timeline from logs alone: a `Staged` event per stage, a `Signaling` event
+    // the moment a slot gets its first support signal, and an
+    // `Activated`/`Dropped` decision per staged slot at rollover.
+    let events = context.events();
+    assert!(events.contains(&FeatureGateEvent::Staged {
+        epoch: 2,
+        feature_id: mock_required_feature_epoch_2,
+    }));
+    assert!(events.contains(&FeatureGateEvent::Signaling {
+        epoch: 2,
+        feature_id: mock_required_feature_epoch_2,
+    }));
+    assert!(events.contains(&FeatureGateEvent::Activated {
+        epoch: 2,
+        feature_id: mock_required_feature_epoch_2,
+    }));
+    assert!(events.contains(&FeatureGateEvent::Dropped {
+        epoch: 2,
+        feature_id: mock_feature_id_epoch_2,
+    }));
 }